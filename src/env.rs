@@ -16,6 +16,14 @@ pub struct Environment {
     /// For example, the key `CONFIG_DEBUG` would become `DEBUG` with a prefix of `config`.
     prefix: Option<String>,
 
+    /// Optional character sequence that separates the prefix from the rest of the key.
+    /// Defaults to `_` for backward compatibility, e.g. a prefix of `config` matches
+    /// `CONFIG_DEBUG`. Set this when [`separator`] is also `_` so the prefix doesn't
+    /// collide with the nesting separator, e.g. `CONFIG__REDIS_HOST`.
+    ///
+    /// [`separator`]: Environment::separator
+    prefix_separator: Option<String>,
+
     /// Optional character sequence that separates each key segment in an environment key pattern.
     /// Consider a nested configuration such as `redis.password`, a separator of `_` would allow
     /// an environment key of `REDIS_PASSWORD` to match.
@@ -24,8 +32,31 @@ pub struct Environment {
     /// Ignore empty env values (treat as unset).
     ignore_empty: bool,
 
-    /// Parse numbers if they're detected.
-    parse_numbers: bool,
+    /// Parse values that look like numbers, booleans, etc. into their detected types.
+    try_parsing: bool,
+
+    /// Optional character sequence that separates the elements of a list. When set, values
+    /// are split on this separator and collected into a `ValueKind::Array`.
+    ///
+    /// See [`Environment::list_parse_keys`] to restrict which keys this applies to.
+    list_separator: Option<String>,
+
+    /// Optional allowlist of keys that should be parsed as a list when [`list_separator`] is
+    /// set. Keys are expressed in the same (post-prefix, post-separator, lowercased) key
+    /// space as the rest of `Environment`. When empty, every key is split.
+    ///
+    /// [`list_separator`]: Environment::list_separator
+    list_parse_keys: Option<Vec<String>>,
+
+    /// Optional allowlist restricting collection to a specific set of keys, applied after
+    /// prefix stripping and separator substitution (the same lowercased, dotted key space
+    /// as the rest of `Environment`). When empty, every key is kept.
+    keep: Option<Vec<String>>,
+
+    /// Optional map of raw environment variable name to target config key, bypassing
+    /// prefix/separator transformation for the mapped entries. Useful for fixed names like
+    /// `DATABASE_URL` or `PORT` that don't follow the crate's nesting convention.
+    rename: Option<HashMap<String, String>>,
 }
 
 impl Environment {
@@ -50,13 +81,72 @@ impl Environment {
         self
     }
 
+    /// Set the separator used between [`Environment::prefix`] and the rest of the key,
+    /// overriding the default of `_`.
+    pub fn prefix_separator(mut self, s: &str) -> Self {
+        self.prefix_separator = Some(s.into());
+        self
+    }
+
     pub fn ignore_empty(mut self, ignore: bool) -> Self {
         self.ignore_empty = ignore;
         self
     }
 
+    /// Parse values that look like integers, floats, or booleans into their detected types.
+    ///
+    /// Values are tried in order: integer, then float, then boolean (`true`/`false`,
+    /// case-insensitive), falling back to a plain string if none match. So `APP_COUNT=0`
+    /// stays an integer rather than being coerced into a boolean-ish string.
+    pub fn try_parsing(mut self, try_parsing: bool) -> Self {
+        self.try_parsing = try_parsing;
+        self
+    }
+
+    /// Deprecated alias for [`Environment::try_parsing`].
+    #[deprecated(since = "0.11.0", note = "please use `try_parsing` instead")]
     pub fn parse_numbers(mut self, parse_numbers: bool) -> Self {
-        self.parse_numbers = parse_numbers;
+        self.try_parsing = parse_numbers;
+        self
+    }
+
+    /// Set a separator to parse list values from a single environment variable. When set,
+    /// a value is split on this separator and collected into a `ValueKind::Array`.
+    ///
+    /// Use [`Environment::list_parse_keys`] to restrict splitting to specific keys, since
+    /// turning this on globally would make it impossible to have both list and scalar
+    /// values in the same environment.
+    pub fn list_separator(mut self, s: &str) -> Self {
+        self.list_separator = Some(s.into());
+        self
+    }
+
+    /// Restrict [`Environment::list_separator`] splitting to this allowlist of keys.
+    ///
+    /// Keys must be given in the same (post-prefix, post-separator, lowercased) key space
+    /// that `Environment` emits, e.g. `vec!["hosts".into()]` or `vec!["redis.hosts".into()]`.
+    /// An empty list (the default) means no restriction: every key is split.
+    pub fn list_parse_keys(mut self, keys: Vec<String>) -> Self {
+        self.list_parse_keys = Some(keys);
+        self
+    }
+
+    /// Restrict collection to this allowlist of keys, regardless of [`Environment::prefix`].
+    ///
+    /// Keys must be given in the same (post-prefix, post-separator, lowercased) key space
+    /// that `Environment` emits, e.g. `vec!["aws.secret".into(), "database.url".into()]`.
+    /// An empty list (the default) means no restriction: every key is kept.
+    pub fn keep(mut self, keys: Vec<String>) -> Self {
+        self.keep = Some(keys);
+        self
+    }
+
+    /// Map raw environment variable names directly to target config keys, bypassing the
+    /// prefix/separator pipeline for those entries.
+    ///
+    /// Unmapped keys continue through the existing prefix/separator transformation as usual.
+    pub fn with_keys(mut self, keys: HashMap<String, String>) -> Self {
+        self.rename = Some(keys);
         self
     }
 }
@@ -65,10 +155,32 @@ impl Default for Environment {
     fn default() -> Environment {
         Environment {
             prefix: None,
+            prefix_separator: None,
             separator: None,
             ignore_empty: false,
-            parse_numbers: false,
+            try_parsing: false,
+            list_separator: None,
+            list_parse_keys: None,
+            keep: None,
+            rename: None,
+        }
+    }
+}
+
+/// Parse a single scalar value, optionally recognizing integers, floats, and booleans.
+fn parse_scalar(try_parsing: bool, value: String) -> ValueKind {
+    if try_parsing {
+        if let Ok(parsed) = value.parse::<i64>() {
+            ValueKind::Integer(parsed)
+        } else if let Ok(parsed) = value.parse::<f64>() {
+            ValueKind::Float(parsed)
+        } else if let Ok(parsed) = value.to_lowercase().parse::<bool>() {
+            ValueKind::Boolean(parsed)
+        } else {
+            ValueKind::String(value)
         }
+    } else {
+        ValueKind::String(value)
     }
 }
 
@@ -87,7 +199,14 @@ impl Source for Environment {
         };
 
         // Define a prefix pattern to test and exclude from keys
-        let prefix_pattern = self.prefix.as_ref().map(|prefix| prefix.clone() + "_");
+        let prefix_separator = match self.prefix_separator {
+            Some(ref prefix_separator) => prefix_separator,
+            _ => "_",
+        };
+        let prefix_pattern = self
+            .prefix
+            .as_ref()
+            .map(|prefix| prefix.clone() + prefix_separator);
 
         for (key, value) in env::vars() {
             // Treat empty environment variables as unset
@@ -95,42 +214,397 @@ impl Source for Environment {
                 continue;
             }
 
-            let mut key = key.to_string();
-
-            // Check for prefix
-            if let Some(ref prefix_pattern) = prefix_pattern {
-                if key
-                    .to_lowercase()
-                    .starts_with(&prefix_pattern.to_lowercase())
-                {
-                    // Remove this prefix from the key
-                    key = key[prefix_pattern.len()..].to_string();
-                } else {
-                    // Skip this key
+            // A raw key found in the rename map is emitted under its mapped key directly,
+            // bypassing the prefix/separator pipeline below.
+            let renamed = self
+                .rename
+                .as_ref()
+                .and_then(|rename| rename.get(&key).cloned());
+
+            let key = if let Some(renamed) = renamed {
+                renamed
+            } else {
+                let mut key = key.to_string();
+
+                // Check for prefix
+                if let Some(ref prefix_pattern) = prefix_pattern {
+                    if key
+                        .to_lowercase()
+                        .starts_with(&prefix_pattern.to_lowercase())
+                    {
+                        // Remove this prefix from the key
+                        key = key[prefix_pattern.len()..].to_string();
+                    } else {
+                        // Skip this key
+                        continue;
+                    }
+                }
+
+                // If separator is given replace with `.`
+                if !separator.is_empty() {
+                    key = key.replace(separator, ".");
+                }
+
+                key.to_lowercase()
+            };
+
+            // If an allowlist is given, skip any key not explicitly kept
+            if let Some(ref keep) = self.keep {
+                if !keep.is_empty() && !keep.contains(&key) {
                     continue;
                 }
             }
 
-            // If separator is given replace with `.`
-            if !separator.is_empty() {
-                key = key.replace(separator, ".");
-            }
+            let value = match self.list_separator {
+                Some(ref list_separator) => {
+                    let should_split = match self.list_parse_keys {
+                        Some(ref keys) if !keys.is_empty() => keys.contains(&key),
+                        _ => true,
+                    };
+
+                    if should_split {
+                        let mut elements: Vec<&str> =
+                            value.split(list_separator.as_str()).collect();
 
-            let value = if self.parse_numbers {
-                if let Ok(parsed) = value.parse() {
-                    ValueKind::Integer(parsed)
-                } else if let Ok(parsed) = value.parse() {
-                    ValueKind::Float(parsed)
-                } else {
-                    ValueKind::String(value)
+                        // A trailing separator would otherwise produce a stray empty
+                        // element (and an empty value would produce a single one).
+                        if elements.last() == Some(&"") {
+                            elements.pop();
+                        }
+
+                        ValueKind::Array(
+                            elements
+                                .into_iter()
+                                .map(|s| {
+                                    let kind = parse_scalar(self.try_parsing, s.to_string());
+                                    Value::new(Some(&uri), kind)
+                                })
+                                .collect(),
+                        )
+                    } else {
+                        parse_scalar(self.try_parsing, value)
+                    }
                 }
-            } else {
-                ValueKind::String(value)
+                None => parse_scalar(self.try_parsing, value),
             };
 
-            m.insert(key.to_lowercase(), Value::new(Some(&uri), value));
+            m.insert(key, Value::new(Some(&uri), value));
         }
 
         Ok(m)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::sync::Mutex;
+
+    // `env::set_var`/`remove_var` touch global process state, so serialize the tests in
+    // this module to avoid them stepping on each other.
+    static LOCK: Mutex<()> = Mutex::new(());
+
+    fn lock() -> std::sync::MutexGuard<'static, ()> {
+        LOCK.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn clear(keys: &[&str]) {
+        for key in keys {
+            env::remove_var(key);
+        }
+    }
+
+    fn string_elements(kind: &ValueKind) -> Vec<String> {
+        match kind {
+            ValueKind::Array(values) => values
+                .iter()
+                .map(|v| match &v.kind {
+                    ValueKind::String(s) => s.clone(),
+                    other => panic!("expected string element, got {:?}", other),
+                })
+                .collect(),
+            other => panic!("expected array, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_list_separator_splits_a_csv_value() {
+        let _guard = lock();
+        clear(&["LIST_HOSTS"]);
+        env::set_var("LIST_HOSTS", "a.com,b.com,c.com");
+
+        let collected = Environment::with_prefix("list")
+            .list_separator(",")
+            .collect()
+            .unwrap();
+
+        assert_eq!(
+            string_elements(&collected["hosts"].kind),
+            vec!["a.com", "b.com", "c.com"]
+        );
+
+        clear(&["LIST_HOSTS"]);
+    }
+
+    #[test]
+    fn test_list_separator_ignores_a_trailing_separator() {
+        let _guard = lock();
+        clear(&["LIST_HOSTS"]);
+        env::set_var("LIST_HOSTS", "a.com,b.com,");
+
+        let collected = Environment::with_prefix("list")
+            .list_separator(",")
+            .collect()
+            .unwrap();
+
+        assert_eq!(
+            string_elements(&collected["hosts"].kind),
+            vec!["a.com", "b.com"]
+        );
+
+        clear(&["LIST_HOSTS"]);
+    }
+
+    #[test]
+    fn test_list_separator_empty_value_yields_empty_array() {
+        let _guard = lock();
+        clear(&["LIST_HOSTS"]);
+        env::set_var("LIST_HOSTS", "");
+
+        let collected = Environment::with_prefix("list")
+            .list_separator(",")
+            .collect()
+            .unwrap();
+
+        assert_eq!(string_elements(&collected["hosts"].kind), Vec::<String>::new());
+
+        clear(&["LIST_HOSTS"]);
+    }
+
+    #[test]
+    fn test_list_parse_keys_restricts_splitting_to_the_allowlist() {
+        let _guard = lock();
+        clear(&["LIST_HOSTS", "LIST_NAME"]);
+        env::set_var("LIST_HOSTS", "a.com,b.com");
+        env::set_var("LIST_NAME", "a,b");
+
+        let collected = Environment::with_prefix("list")
+            .list_separator(",")
+            .list_parse_keys(vec!["hosts".into()])
+            .collect()
+            .unwrap();
+
+        assert_eq!(
+            string_elements(&collected["hosts"].kind),
+            vec!["a.com", "b.com"]
+        );
+        match &collected["name"].kind {
+            ValueKind::String(s) => assert_eq!(s, "a,b"),
+            other => panic!("expected string, got {:?}", other),
+        }
+
+        clear(&["LIST_HOSTS", "LIST_NAME"]);
+    }
+
+    #[test]
+    fn test_list_separator_combined_with_try_parsing_parses_each_element() {
+        let _guard = lock();
+        clear(&["LIST_PORTS"]);
+        env::set_var("LIST_PORTS", "80,443,8080");
+
+        let collected = Environment::with_prefix("list")
+            .list_separator(",")
+            .try_parsing(true)
+            .collect()
+            .unwrap();
+
+        let ports: Vec<ValueKind> = match &collected["ports"].kind {
+            ValueKind::Array(values) => values.iter().map(|v| v.kind.clone()).collect(),
+            other => panic!("expected array, got {:?}", other),
+        };
+        assert_eq!(
+            ports,
+            vec![
+                ValueKind::Integer(80),
+                ValueKind::Integer(443),
+                ValueKind::Integer(8080),
+            ]
+        );
+
+        clear(&["LIST_PORTS"]);
+    }
+
+    #[test]
+    fn test_try_parsing_recognizes_integers_floats_and_booleans() {
+        let _guard = lock();
+        clear(&["TYPED_COUNT", "TYPED_RATIO", "TYPED_DEBUG"]);
+        env::set_var("TYPED_COUNT", "0");
+        env::set_var("TYPED_RATIO", "1.5");
+        env::set_var("TYPED_DEBUG", "TRUE");
+
+        let collected = Environment::with_prefix("typed")
+            .try_parsing(true)
+            .collect()
+            .unwrap();
+
+        assert_eq!(collected["count"].kind, ValueKind::Integer(0));
+        assert_eq!(collected["ratio"].kind, ValueKind::Float(1.5));
+        assert_eq!(collected["debug"].kind, ValueKind::Boolean(true));
+
+        clear(&["TYPED_COUNT", "TYPED_RATIO", "TYPED_DEBUG"]);
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_parse_numbers_alias_still_behaves_like_try_parsing() {
+        let _guard = lock();
+        clear(&["ALIAS_COUNT"]);
+        env::set_var("ALIAS_COUNT", "42");
+
+        let collected = Environment::with_prefix("alias")
+            .parse_numbers(true)
+            .collect()
+            .unwrap();
+
+        assert_eq!(collected["count"].kind, ValueKind::Integer(42));
+
+        clear(&["ALIAS_COUNT"]);
+    }
+
+    #[test]
+    fn test_keep_retains_only_allowlisted_keys() {
+        let _guard = lock();
+        clear(&["KEEP_DEBUG", "KEEP_NAME"]);
+        env::set_var("KEEP_DEBUG", "true");
+        env::set_var("KEEP_NAME", "foo");
+
+        let collected = Environment::with_prefix("keep")
+            .keep(vec!["debug".into()])
+            .collect()
+            .unwrap();
+
+        assert!(collected.contains_key("debug"));
+        assert!(!collected.contains_key("name"));
+
+        clear(&["KEEP_DEBUG", "KEEP_NAME"]);
+    }
+
+    #[test]
+    fn test_keep_empty_allowlist_is_a_no_op() {
+        let _guard = lock();
+        clear(&["KEEP_DEBUG", "KEEP_NAME"]);
+        env::set_var("KEEP_DEBUG", "true");
+        env::set_var("KEEP_NAME", "foo");
+
+        let collected = Environment::with_prefix("keep")
+            .keep(vec![])
+            .collect()
+            .unwrap();
+
+        assert!(collected.contains_key("debug"));
+        assert!(collected.contains_key("name"));
+
+        clear(&["KEEP_DEBUG", "KEEP_NAME"]);
+    }
+
+    #[test]
+    fn test_keep_without_a_prefix_restricts_the_whole_environment() {
+        let _guard = lock();
+        clear(&["AWS_SECRET"]);
+        env::set_var("AWS_SECRET", "s3cr3t");
+
+        let collected = Environment::new()
+            .keep(vec!["aws_secret".into()])
+            .collect()
+            .unwrap();
+
+        assert_eq!(collected.len(), 1);
+        match &collected["aws_secret"].kind {
+            ValueKind::String(s) => assert_eq!(s, "s3cr3t"),
+            other => panic!("expected string, got {:?}", other),
+        }
+
+        clear(&["AWS_SECRET"]);
+    }
+
+    #[test]
+    fn test_prefix_separator_distinct_from_nesting_separator() {
+        let _guard = lock();
+        clear(&["PFXSEP__REDIS_HOST"]);
+        env::set_var("PFXSEP__REDIS_HOST", "redis.example.com");
+
+        let collected = Environment::with_prefix("pfxsep")
+            .prefix_separator("__")
+            .separator("_")
+            .collect()
+            .unwrap();
+
+        match &collected["redis.host"].kind {
+            ValueKind::String(s) => assert_eq!(s, "redis.example.com"),
+            other => panic!("expected string, got {:?}", other),
+        }
+
+        clear(&["PFXSEP__REDIS_HOST"]);
+    }
+
+    #[test]
+    fn test_with_keys_bypasses_prefix_and_separator() {
+        let _guard = lock();
+        clear(&["RENAME_DATABASE_URL", "RENAME_NAME"]);
+        env::set_var("RENAME_DATABASE_URL", "postgres://localhost/app");
+        env::set_var("RENAME_NAME", "foo");
+
+        let mut keys = HashMap::new();
+        keys.insert(
+            "RENAME_DATABASE_URL".to_string(),
+            "database.url".to_string(),
+        );
+
+        let collected = Environment::with_prefix("unrelated")
+            .with_keys(keys)
+            .collect()
+            .unwrap();
+
+        // The renamed key is emitted even though it doesn't match the "unrelated" prefix.
+        match &collected["database.url"].kind {
+            ValueKind::String(s) => assert_eq!(s, "postgres://localhost/app"),
+            other => panic!("expected string, got {:?}", other),
+        }
+
+        // An unmapped key in the same environment is untouched by the rename map and
+        // simply doesn't match the configured prefix.
+        assert!(!collected.contains_key("name"));
+
+        clear(&["RENAME_DATABASE_URL", "RENAME_NAME"]);
+    }
+
+    #[test]
+    fn test_unmapped_keys_still_go_through_the_normal_pipeline() {
+        let _guard = lock();
+        clear(&["RENAME2_DATABASE_URL", "RENAME2_NAME"]);
+        env::set_var("RENAME2_DATABASE_URL", "postgres://localhost/app");
+        env::set_var("RENAME2_NAME", "foo");
+
+        let mut keys = HashMap::new();
+        keys.insert(
+            "RENAME2_DATABASE_URL".to_string(),
+            "database.url".to_string(),
+        );
+
+        let collected = Environment::with_prefix("rename2")
+            .with_keys(keys)
+            .collect()
+            .unwrap();
+
+        match &collected["database.url"].kind {
+            ValueKind::String(s) => assert_eq!(s, "postgres://localhost/app"),
+            other => panic!("expected string, got {:?}", other),
+        }
+        match &collected["name"].kind {
+            ValueKind::String(s) => assert_eq!(s, "foo"),
+            other => panic!("expected string, got {:?}", other),
+        }
+
+        clear(&["RENAME2_DATABASE_URL", "RENAME2_NAME"]);
+    }
+}